@@ -0,0 +1,45 @@
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::SearchMode;
+
+/// Saved search patterns and the chosen search mode, persisted across
+/// sessions so numbered slots (1-9) still resolve after `logview` restarts.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Config {
+    #[serde(default)]
+    pub saved_patterns: Vec<String>,
+    #[serde(default)]
+    pub search_mode: SearchMode,
+}
+
+fn config_path() -> Option<PathBuf> {
+    let dirs = directories::ProjectDirs::from("", "", "logview")?;
+    Some(dirs.config_dir().join("config.json"))
+}
+
+/// Loads the saved config, falling back to defaults if it doesn't exist
+/// yet or can't be parsed.
+pub fn load() -> Config {
+    config_path()
+        .and_then(|path| fs::read_to_string(path).ok())
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+/// Writes `config` to the user config directory, creating it if needed.
+/// Silently does nothing if the config directory can't be determined.
+pub fn save(config: &Config) -> io::Result<()> {
+    let Some(path) = config_path() else {
+        return Ok(());
+    };
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let contents = serde_json::to_string_pretty(config)
+        .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+    fs::write(path, contents)
+}
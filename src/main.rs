@@ -1,5 +1,10 @@
+use std::collections::HashSet;
 use std::fs::File;
 use std::io::{self, BufRead, BufReader};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
 use tui::{
     backend::CrosstermBackend,
     widgets::{Block, Borders, List, ListItem},
@@ -12,13 +17,154 @@ use crossterm::{
     terminal::{disable_raw_mode, enable_raw_mode, Clear, ClearType, EnterAlternateScreen},
     execute,
 };
+use fuzzy_matcher::skim::SkimMatcherV2;
+use fuzzy_matcher::FuzzyMatcher;
 use regex::Regex;
 use clap::Parser;
+use serde::{Deserialize, Serialize};
+use walkdir::WalkDir;
+
+mod config;
 
 #[derive(Debug)]
 struct LogEntry {
-    raw_content: String,
+    /// The line's text with ANSI escape sequences stripped out - i.e.
+    /// exactly what `styled_spans` renders. Search and highlighting both
+    /// index into this, so match positions line up with what's on screen.
+    display_content: String,
     styled_spans: Vec<Spans<'static>>,  // スタイル情報を保持
+    source: LogSource,
+}
+
+/// Where a `LogEntry` came from, analogous to strider's `SearchResult`.
+/// `SingleFile` is used when `logview` was pointed at one file directly;
+/// `LineInFile` tags a line found while walking a directory, so it can be
+/// displayed with its originating path and line number.
+#[derive(Debug, Clone)]
+enum LogSource {
+    SingleFile,
+    LineInFile { path: PathBuf, line_number: usize },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+enum SearchMode {
+    Regex,
+    Fuzzy,
+}
+
+impl Default for SearchMode {
+    fn default() -> Self {
+        SearchMode::Regex
+    }
+}
+
+/// A log line selected by the current search, paired with the spans it
+/// should actually be drawn with (which may carry match highlighting on
+/// top of the line's own ANSI styling).
+struct FilteredLine<'a> {
+    log: &'a LogEntry,
+    display: Vec<Spans<'static>>,
+}
+
+fn fuzzy_highlight_style() -> Style {
+    Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+}
+
+fn regex_highlight_style() -> Style {
+    Style::default().add_modifier(Modifier::REVERSED)
+}
+
+/// Renders `log` with `highlight` layered onto the characters listed in
+/// `positions` (char indices into `log.display_content`), on top of its
+/// existing ANSI styling.
+fn render_with_highlights(log: &LogEntry, positions: &HashSet<usize>, highlight: Style) -> Vec<Spans<'static>> {
+    log.styled_spans.iter()
+        .map(|line| Spans::from(apply_highlights(&line.0, positions, highlight)))
+        .collect()
+}
+
+/// Prepends a `path:line_number: ` prefix to `body` when `log` came from a
+/// directory walk, so hits can be traced back to the file they came from.
+fn with_source_prefix(log: &LogEntry, body: Vec<Spans<'static>>) -> Vec<Spans<'static>> {
+    let LogSource::LineInFile { path, line_number } = &log.source else {
+        return body;
+    };
+
+    let prefix = Span::styled(
+        format!("{}:{}: ", path.display(), line_number),
+        Style::default().fg(Color::DarkGray),
+    );
+
+    let mut lines = body.into_iter();
+    let mut result = Vec::new();
+    if let Some(first) = lines.next() {
+        let mut spans = vec![prefix];
+        spans.extend(first.0);
+        result.push(Spans::from(spans));
+    }
+    result.extend(lines);
+    result
+}
+
+fn render_line<'a>(log: &'a LogEntry, body: Vec<Spans<'static>>) -> FilteredLine<'a> {
+    FilteredLine { log, display: with_source_prefix(log, body) }
+}
+
+/// Char indices (into `raw`) covered by any of the regex's matches.
+/// `find_iter` yields non-overlapping matches in increasing byte order, so
+/// a single pass over `char_indices` - advancing to the next match once the
+/// current one is behind us - is enough; no need to rescan from the start
+/// of the line for every match.
+fn regex_match_positions(regex: &Regex, raw: &str) -> HashSet<usize> {
+    let mut positions = HashSet::new();
+    let mut matches = regex.find_iter(raw).peekable();
+
+    for (char_idx, (byte_idx, _)) in raw.char_indices().enumerate() {
+        while matches.peek().is_some_and(|m| byte_idx >= m.end()) {
+            matches.next();
+        }
+        if matches.peek().is_some_and(|m| byte_idx >= m.start() && byte_idx < m.end()) {
+            positions.insert(char_idx);
+        }
+    }
+
+    positions
+}
+
+/// Re-styles `spans` so that the characters whose char index (counted
+/// across the whole line) appears in `positions` get `highlight` layered
+/// on top of their existing style, while everything else keeps it.
+fn apply_highlights(spans: &[Span<'static>], positions: &HashSet<usize>, highlight: Style) -> Vec<Span<'static>> {
+    let mut result = Vec::new();
+    let mut idx = 0usize;
+
+    for span in spans {
+        let mut current = String::new();
+        let mut current_highlighted = false;
+        let mut first = true;
+
+        for ch in span.content.chars() {
+            let highlighted = positions.contains(&idx);
+            if first {
+                current_highlighted = highlighted;
+                first = false;
+            } else if highlighted != current_highlighted {
+                let style = if current_highlighted { span.style.patch(highlight) } else { span.style };
+                result.push(Span::styled(current.clone(), style));
+                current.clear();
+                current_highlighted = highlighted;
+            }
+            current.push(ch);
+            idx += 1;
+        }
+
+        if !current.is_empty() {
+            let style = if current_highlighted { span.style.patch(highlight) } else { span.style };
+            result.push(Span::styled(current, style));
+        }
+    }
+
+    result
 }
 
 struct App {
@@ -26,32 +172,131 @@ struct App {
     search_pattern: String,
     is_searching: bool,
     search_regex: Option<Regex>,
+    search_mode: SearchMode,
+    fuzzy_matcher: SkimMatcherV2,
     saved_patterns: Vec<String>,
     scroll: usize,
 }
 
 impl App {
     fn new(logs: Vec<LogEntry>) -> Self {
+        let config = config::load();
         Self {
             logs,
             search_pattern: String::new(),
             is_searching: false,
             search_regex: None,
-            saved_patterns: Vec::new(),
+            search_mode: config.search_mode,
+            fuzzy_matcher: SkimMatcherV2::default(),
+            saved_patterns: config.saved_patterns,
             scroll: 0,
         }
     }
 
-    fn filtered_logs(&self) -> Vec<&LogEntry> {
-        match &self.search_regex {
-            Some(regex) => self.logs
-                .iter()
-                .filter(|log| regex.is_match(&log.raw_content))
-                .collect(),
-            None => self.logs.iter().collect(),
+    fn toggle_search_mode(&mut self) {
+        self.search_mode = match self.search_mode {
+            SearchMode::Regex => SearchMode::Fuzzy,
+            SearchMode::Fuzzy => SearchMode::Regex,
+        };
+        self.save_config();
+    }
+
+    /// Persists the saved patterns and current search mode so they survive
+    /// the next launch. Failures (e.g. no writable config dir) are ignored.
+    fn save_config(&self) {
+        let config = config::Config {
+            saved_patterns: self.saved_patterns.clone(),
+            search_mode: self.search_mode,
+        };
+        let _ = config::save(&config);
+    }
+
+    /// Logs that pass the current search, in display order, paired with
+    /// precomputed fuzzy-match indices where ranking already produced them.
+    /// Cheap: no spans are cloned or rebuilt here, only matching/sorting.
+    fn matched_entries(&self) -> Vec<(&LogEntry, Option<Vec<usize>>)> {
+        match self.search_mode {
+            SearchMode::Regex => match &self.search_regex {
+                Some(regex) => self.logs
+                    .iter()
+                    .filter(|log| regex.is_match(&log.display_content))
+                    .map(|log| (log, None))
+                    .collect(),
+                None => self.logs.iter().map(|log| (log, None)).collect(),
+            },
+            SearchMode::Fuzzy => {
+                if self.search_pattern.is_empty() {
+                    return self.logs.iter().map(|log| (log, None)).collect();
+                }
+
+                let mut scored: Vec<(i64, &LogEntry, Vec<usize>)> = self.logs
+                    .iter()
+                    .filter_map(|log| {
+                        self.fuzzy_matcher
+                            .fuzzy_indices(&log.display_content, &self.search_pattern)
+                            .map(|(score, indices)| (score, log, indices))
+                    })
+                    .collect();
+                scored.sort_by(|a, b| b.0.cmp(&a.0));
+
+                scored.into_iter().map(|(_, log, indices)| (log, Some(indices))).collect()
+            }
         }
     }
 
+    /// Number of logs that pass the current search. Unlike
+    /// `matched_entries`, fuzzy mode uses `fuzzy_match` here instead of
+    /// `fuzzy_indices`, since callers only need a count and not the
+    /// per-character match positions.
+    fn match_count(&self) -> usize {
+        match self.search_mode {
+            SearchMode::Regex => match &self.search_regex {
+                Some(regex) => self.logs.iter().filter(|log| regex.is_match(&log.display_content)).count(),
+                None => self.logs.len(),
+            },
+            SearchMode::Fuzzy => {
+                if self.search_pattern.is_empty() {
+                    self.logs.len()
+                } else {
+                    self.logs
+                        .iter()
+                        .filter(|log| self.fuzzy_matcher.fuzzy_match(&log.display_content, &self.search_pattern).is_some())
+                        .count()
+                }
+            }
+        }
+    }
+
+    /// Renders only the `take` matched logs starting at `skip`, so the
+    /// (comparatively expensive) highlight rebuilding and span cloning only
+    /// happens for the rows that are actually about to be drawn.
+    fn render_window(&self, skip: usize, take: usize) -> Vec<FilteredLine> {
+        let regex_highlight = regex_highlight_style();
+        let fuzzy_highlight = fuzzy_highlight_style();
+
+        self.matched_entries()
+            .into_iter()
+            .skip(skip)
+            .take(take)
+            .map(|(log, indices)| {
+                let body = match indices {
+                    Some(indices) => {
+                        let positions: HashSet<usize> = indices.into_iter().collect();
+                        render_with_highlights(log, &positions, fuzzy_highlight)
+                    }
+                    None => match &self.search_regex {
+                        Some(regex) if self.search_mode == SearchMode::Regex => {
+                            let positions = regex_match_positions(regex, &log.display_content);
+                            render_with_highlights(log, &positions, regex_highlight)
+                        }
+                        _ => log.styled_spans.clone(),
+                    },
+                };
+                render_line(log, body)
+            })
+            .collect()
+    }
+
     fn load_pattern(&mut self, key: u8) {
         let idx = key as usize;
         if key == 0 {
@@ -81,7 +326,7 @@ impl App {
     }
 
     fn scroll_down(&mut self, height: usize) {
-        let max_scroll = self.filtered_logs().len().saturating_sub(height);
+        let max_scroll = self.match_count().saturating_sub(height);
         if self.scroll < max_scroll {
             self.scroll += 1;
         }
@@ -92,22 +337,41 @@ impl App {
     }
 
     fn page_down(&mut self, height: usize) {
-        let max_scroll = self.filtered_logs().len().saturating_sub(height);
+        let max_scroll = self.match_count().saturating_sub(height);
         self.scroll = (self.scroll + height).min(max_scroll);
     }
 
+    fn is_at_bottom(&self, height: usize) -> bool {
+        self.scroll >= self.match_count().saturating_sub(height)
+    }
+
+    fn scroll_to_bottom(&mut self, height: usize) {
+        self.scroll = self.match_count().saturating_sub(height);
+    }
+
     fn confirm_search(&mut self) {
         self.is_searching = false;
-        if !self.search_pattern.is_empty() {
-            if let Ok(regex) = Regex::new(&self.search_pattern) {
-                self.search_regex = Some(regex);
-                if !self.saved_patterns.contains(&self.search_pattern) {
-                    if self.saved_patterns.len() >= 10 {
-                        self.saved_patterns.remove(0);
-                    }
-                    self.saved_patterns.push(self.search_pattern.clone());
+        if self.search_pattern.is_empty() {
+            return;
+        }
+
+        let should_save = match self.search_mode {
+            SearchMode::Regex => match Regex::new(&self.search_pattern) {
+                Ok(regex) => {
+                    self.search_regex = Some(regex);
+                    true
                 }
+                Err(_) => false,
+            },
+            SearchMode::Fuzzy => true,
+        };
+
+        if should_save && !self.saved_patterns.contains(&self.search_pattern) {
+            if self.saved_patterns.len() >= 10 {
+                self.saved_patterns.remove(0);
             }
+            self.saved_patterns.push(self.search_pattern.clone());
+            self.save_config();
         }
     }
 }
@@ -115,20 +379,29 @@ impl App {
 #[derive(Parser)]
 #[command(name = "log-viewer")]
 struct Args {
-    #[arg(help = "Path to the log file")]
-    log_file: String,
+    #[arg(help = "Path to a log file, or a directory to search recursively")]
+    path: String,
+
+    #[arg(short, long, help = "Tail the file, streaming appended lines as they arrive")]
+    follow: bool,
 }
 
 fn main() -> Result<(), io::Error> {
     let args = Args::parse();
-    
-    let file = File::open(&args.log_file)?;
-    let reader = BufReader::new(file);
-    let logs: Vec<LogEntry> = reader
-        .lines()
-        .filter_map(|line| line.ok())
-        .filter_map(|line| parse_log_line(&line))
-        .collect();
+    let path = Path::new(&args.path);
+
+    if args.follow && path.is_dir() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "--follow requires a single file, not a directory",
+        ));
+    }
+
+    let (logs, log_rx) = if args.follow {
+        (Vec::new(), Some(spawn_follower(path.to_path_buf())))
+    } else {
+        (load_logs(path)?, None)
+    };
 
     enable_raw_mode()?;
     let mut stdout = io::stdout();
@@ -147,46 +420,74 @@ fn main() -> Result<(), io::Error> {
     loop {
         let size = terminal.size()?;
         let height = size.height as usize;
+        let mut dirty = false;
 
-        if let Event::Key(key) = event::read()? {
-            match key.code {
-                KeyCode::Up => app.scroll_up(),
-                KeyCode::Down => app.scroll_down(height),
-                KeyCode::PageUp => app.page_up(height),
-                KeyCode::PageDown => app.page_down(height),
-                KeyCode::Char('q') if !app.is_searching => break,
-                KeyCode::Char('/') if !app.is_searching => {
-                    app.is_searching = true;
-                },
-                KeyCode::Enter if app.is_searching => {
-                    app.confirm_search();
-                },
-                KeyCode::Char(c) if !app.is_searching && c.is_ascii_digit() => {
-                    app.load_pattern(c as u8 - b'0');
-                },
-                KeyCode::Esc if app.is_searching => {
-                    app.is_searching = false;
-                    app.search_pattern.clear();
-                    app.search_regex = None;
-                },
-                KeyCode::Backspace if app.is_searching => {
-                    app.search_pattern.pop();
-                },
-                KeyCode::Char(c) => {
-                    if key.code == KeyCode::Char(' ') && !app.is_searching {
-                        app.page_down(height);
-                    } else if app.is_searching {
-                        app.search_pattern.push(c);
+        if event::poll(Duration::from_millis(100))? {
+            match event::read()? {
+                Event::Key(key) => {
+                    dirty = true;
+                    match key.code {
+                        KeyCode::Up => app.scroll_up(),
+                        KeyCode::Down => app.scroll_down(height),
+                        KeyCode::PageUp => app.page_up(height),
+                        KeyCode::PageDown => app.page_down(height),
+                        KeyCode::Char('q') if !app.is_searching => break,
+                        KeyCode::Char('/') if !app.is_searching => {
+                            app.is_searching = true;
+                        },
+                        KeyCode::Char('f') if !app.is_searching => {
+                            app.toggle_search_mode();
+                        },
+                        KeyCode::Enter if app.is_searching => {
+                            app.confirm_search();
+                        },
+                        KeyCode::Char(c) if !app.is_searching && c.is_ascii_digit() => {
+                            app.load_pattern(c as u8 - b'0');
+                        },
+                        KeyCode::Esc if app.is_searching => {
+                            app.is_searching = false;
+                            app.search_pattern.clear();
+                            app.search_regex = None;
+                        },
+                        KeyCode::Backspace if app.is_searching => {
+                            app.search_pattern.pop();
+                        },
+                        KeyCode::Char(c) => {
+                            if key.code == KeyCode::Char(' ') && !app.is_searching {
+                                app.page_down(height);
+                            } else if app.is_searching {
+                                app.search_pattern.push(c);
+                            }
+                        },
+                        _ => {}
                     }
-                },
+                }
+                Event::Resize(_, _) => dirty = true,
                 _ => {}
             }
         }
 
-        terminal.draw(|f| {
-            let size = f.size();
-            draw_logs(&app, f, size);
-        })?;
+        if let Some(rx) = &log_rx {
+            let mut new_entries = Vec::new();
+            while let Ok(entry) = rx.try_recv() {
+                new_entries.push(entry);
+            }
+            if !new_entries.is_empty() {
+                let was_at_bottom = app.is_at_bottom(height);
+                app.logs.extend(new_entries);
+                dirty = true;
+                if was_at_bottom {
+                    app.scroll_to_bottom(height);
+                }
+            }
+        }
+
+        if dirty {
+            terminal.draw(|f| {
+                let size = f.size();
+                draw_logs(&app, f, size);
+            })?;
+        }
     }
 
     disable_raw_mode()?;
@@ -196,11 +497,9 @@ fn main() -> Result<(), io::Error> {
 fn draw_logs(app: &App, f: &mut tui::Frame<CrosstermBackend<io::Stdout>>, size: tui::layout::Rect) {
     let height = size.height as usize - 2;
     
-    let items: Vec<ListItem> = app.filtered_logs()
-        .iter()
-        .skip(app.scroll)
-        .take(height)
-        .map(|log| ListItem::new(log.styled_spans.clone()))
+    let items: Vec<ListItem> = app.render_window(app.scroll, height)
+        .into_iter()
+        .map(|line| ListItem::new(line.display))
         .chain(std::iter::repeat(ListItem::new(vec![
             Spans::from(vec![Span::raw("")])
         ])).take(height))
@@ -212,7 +511,11 @@ fn draw_logs(app: &App, f: &mut tui::Frame<CrosstermBackend<io::Stdout>>, size:
             .borders(Borders::TOP)
             .title(
                 if app.is_searching {
-                    format!("Search: {}_", app.search_pattern)
+                    let mode = match app.search_mode {
+                        SearchMode::Regex => "regex",
+                        SearchMode::Fuzzy => "fuzzy",
+                    };
+                    format!("Search [{}]: {}_", mode, app.search_pattern)
                 } else {
                     format!("Log Viewer - {}", app.get_status_text())
                 }
@@ -221,6 +524,73 @@ fn draw_logs(app: &App, f: &mut tui::Frame<CrosstermBackend<io::Stdout>>, size:
     f.render_widget(list, size);
 }
 
+/// Maps the 8 basic ANSI color numbers (0-7, as used by both the
+/// `30-37` foreground and `40-47` background ranges) to `tui` colors.
+fn ansi_8_color(n: u8) -> Color {
+    match n {
+        0 => Color::Black,
+        1 => Color::Red,
+        2 => Color::Green,
+        3 => Color::Yellow,
+        4 => Color::Blue,
+        5 => Color::Magenta,
+        6 => Color::Cyan,
+        7 => Color::White,
+        _ => Color::Reset,
+    }
+}
+
+/// Applies a full SGR parameter list to `style`, accumulating onto it
+/// (rather than replacing it) the way real terminals do, so sequences
+/// like `\x1b[1;31m` (bold *and* red) both take effect. Supports the
+/// basic 8-color and bright-reset codes, 256-color and truecolor
+/// extended codes (`38;5;n` / `38;2;r;g;b` and their `48;...`
+/// background equivalents), and the common attribute toggles.
+fn apply_sgr_params(mut style: Style, params: &[u8]) -> Style {
+    let mut i = 0;
+    while i < params.len() {
+        match params[i] {
+            0 => style = Style::default(),
+            1 => style = style.add_modifier(Modifier::BOLD),
+            4 => style = style.add_modifier(Modifier::UNDERLINED),
+            7 => style = style.add_modifier(Modifier::REVERSED),
+            22 => style = style.remove_modifier(Modifier::BOLD),
+            24 => style = style.remove_modifier(Modifier::UNDERLINED),
+            27 => style = style.remove_modifier(Modifier::REVERSED),
+            30..=37 => style = style.fg(ansi_8_color(params[i] - 30)),
+            39 => style = style.fg(Color::Reset),
+            40..=47 => style = style.bg(ansi_8_color(params[i] - 40)),
+            49 => style = style.bg(Color::Reset),
+            38 | 48 => {
+                let is_fg = params[i] == 38;
+                match params.get(i + 1) {
+                    Some(5) => {
+                        if let Some(&n) = params.get(i + 2) {
+                            let color = Color::Indexed(n);
+                            style = if is_fg { style.fg(color) } else { style.bg(color) };
+                        }
+                        i += 2;
+                    }
+                    Some(2) => {
+                        if let (Some(&r), Some(&g), Some(&b)) =
+                            (params.get(i + 2), params.get(i + 3), params.get(i + 4))
+                        {
+                            let color = Color::Rgb(r, g, b);
+                            style = if is_fg { style.fg(color) } else { style.bg(color) };
+                        }
+                        i += 4;
+                    }
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+
+    style
+}
+
 fn parse_log_line(line: &str) -> Option<LogEntry> {
     if line.trim().is_empty() {
         return None;
@@ -230,16 +600,16 @@ fn parse_log_line(line: &str) -> Option<LogEntry> {
     let mut current_text = String::new();
     let mut current_style = Style::default();
     let mut chars = line.chars().peekable();
-    
+
     while let Some(c) = chars.next() {
         if c == '\x1B' && chars.peek() == Some(&'[') {
             chars.next();
-            
+
             if !current_text.is_empty() {
                 spans.push(Span::styled(current_text.clone(), current_style));
                 current_text.clear();
             }
-            
+
             let mut code = String::new();
             while let Some(c) = chars.next() {
                 if c.is_ascii_alphabetic() {
@@ -247,29 +617,104 @@ fn parse_log_line(line: &str) -> Option<LogEntry> {
                 }
                 code.push(c);
             }
-            
-            current_style = match code.as_str() {
-                "31" => Style::default().fg(Color::Red),
-                "32" => Style::default().fg(Color::Green),
-                "33" => Style::default().fg(Color::Yellow),
-                "34" => Style::default().fg(Color::Blue),
-                "35" => Style::default().fg(Color::Magenta),
-                "36" => Style::default().fg(Color::Cyan),
-                "1" => Style::default().add_modifier(Modifier::BOLD),
-                "0" => Style::default(),
-                _ => current_style,
-            };
+
+            let params: Vec<u8> = code.split(';').map(|p| p.parse().unwrap_or(0)).collect();
+            current_style = apply_sgr_params(current_style, &params);
         } else {
             current_text.push(c);
         }
     }
-    
+
     if !current_text.is_empty() {
         spans.push(Span::styled(current_text, current_style));
     }
 
+    let display_content: String = spans.iter().map(|span| span.content.as_ref()).collect();
+
     Some(LogEntry {
-        raw_content: line.to_string(),
+        display_content,
         styled_spans: vec![Spans::from(spans)],
+        source: LogSource::SingleFile,
     })
+}
+
+/// Loads log entries from `path`, which may be a single file (read as
+/// before) or a directory, in which case it is walked recursively like
+/// `walkdir`-based tools such as strider, skipping binary/non-UTF8 files.
+fn load_logs(path: &Path) -> io::Result<Vec<LogEntry>> {
+    if path.is_dir() {
+        load_logs_from_dir(path)
+    } else {
+        load_logs_from_file(path)
+    }
+}
+
+fn load_logs_from_file(path: &Path) -> io::Result<Vec<LogEntry>> {
+    let file = File::open(path)?;
+    let reader = BufReader::new(file);
+    Ok(reader
+        .lines()
+        .filter_map(|line| line.ok())
+        .filter_map(|line| parse_log_line(&line))
+        .collect())
+}
+
+/// Spawns a background thread that owns the `BufReader` for `path`,
+/// parsing lines as they become available and sending them over the
+/// returned channel. After reaching EOF it keeps polling for bytes
+/// appended to the file, so the caller can tail a growing log without
+/// blocking the UI thread.
+fn spawn_follower(path: PathBuf) -> mpsc::Receiver<LogEntry> {
+    let (tx, rx) = mpsc::channel();
+
+    thread::spawn(move || {
+        let file = match File::open(&path) {
+            Ok(file) => file,
+            Err(_) => return,
+        };
+        let mut reader = BufReader::new(file);
+        let mut line = String::new();
+
+        loop {
+            line.clear();
+            match reader.read_line(&mut line) {
+                Ok(0) => thread::sleep(Duration::from_millis(200)),
+                Ok(_) => {
+                    let trimmed = line.trim_end_matches('\n').trim_end_matches('\r');
+                    if let Some(entry) = parse_log_line(trimmed) {
+                        if tx.send(entry).is_err() {
+                            break;
+                        }
+                    }
+                }
+                Err(_) => break,
+            }
+        }
+    });
+
+    rx
+}
+
+fn load_logs_from_dir(root: &Path) -> io::Result<Vec<LogEntry>> {
+    let mut logs = Vec::new();
+
+    for entry in WalkDir::new(root).into_iter().filter_map(|e| e.ok()) {
+        if !entry.file_type().is_file() {
+            continue;
+        }
+
+        let Ok(content) = std::fs::read_to_string(entry.path()) else {
+            continue; // binary or non-UTF8 file, skip it
+        };
+        let rel_path = entry.path().strip_prefix(root).unwrap_or(entry.path()).to_path_buf();
+
+        for (idx, line) in content.lines().enumerate() {
+            if let Some(mut log) = parse_log_line(line) {
+                log.source = LogSource::LineInFile { path: rel_path.clone(), line_number: idx + 1 };
+                logs.push(log);
+            }
+        }
+    }
+
+    Ok(logs)
 }
\ No newline at end of file